@@ -0,0 +1,111 @@
+//! Derive macro crate for `odbc_iter::TryFromValueRow`.
+//!
+//! `#[derive(TryFromValueRow)]` maps each struct field to the column of the same name (case
+//! insensitive) in the `schema` passed to `try_from_row`, instead of relying on column order like
+//! the tuple and scalar implementations in `odbc_iter` do. With the `derive` feature of the main
+//! crate enabled, this is re-exported as `odbc_iter::TryFromValueRow` alongside the trait of the
+//! same name, so callers only need `use odbc_iter::TryFromValueRow;`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+
+/// Derives `TryFromValueRow` for a struct with named fields, matching fields to columns by name.
+///
+/// Fields of type `Option<T>` are allowed to be `NULL`; all other fields return
+/// `RowConvertError::UnexpectedNullValue` naming the field when the matching column is `NULL`.
+/// A column missing from `schema` is reported as `RowConvertError::ColumnNotFound`.
+#[proc_macro_derive(TryFromValueRow)]
+pub fn derive_try_from_value_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    ident,
+                    "TryFromValueRow can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(ident, "TryFromValueRow can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let field_conversions = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_name = field_ident.to_string();
+
+        if is_option_type(&field.ty) {
+            quote! {
+                #field_ident: {
+                    let column = schema
+                        .iter()
+                        .position(|column| column.name.eq_ignore_ascii_case(#field_name))
+                        .ok_or(odbc_iter::RowConvertError::ColumnNotFound(#field_name))?;
+                    odbc_iter::TryFromValue::try_from_value(values[column].take())
+                        .map_err(|err| odbc_iter::RowConvertError::ValueConvertError(Box::new(err)))?
+                }
+            }
+        } else {
+            quote! {
+                #field_ident: {
+                    let column = schema
+                        .iter()
+                        .position(|column| column.name.eq_ignore_ascii_case(#field_name))
+                        .ok_or(odbc_iter::RowConvertError::ColumnNotFound(#field_name))?;
+                    match values[column].take() {
+                        None => return Err(odbc_iter::RowConvertError::UnexpectedNullValue(#field_name)),
+                        Some(value) => odbc_iter::TryFromValue::try_from_value(Some(value))
+                            .map_err(|err| odbc_iter::RowConvertError::ValueConvertError(Box::new(err)))?,
+                    }
+                }
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl odbc_iter::TryFromValueRow for #ident {
+            type Error = odbc_iter::RowConvertError;
+
+            fn try_from_row(
+                values: odbc_iter::ValueRow,
+                schema: &[odbc_iter::ColumnType],
+            ) -> Result<Self, Self::Error> {
+                let mut values: odbc_iter::ValueRow = values;
+                if values.len() != schema.len() {
+                    return Err(odbc_iter::RowConvertError::UnexpectedNumberOfColumns {
+                        expected: schema.len() as u16,
+                        got: values.len(),
+                    });
+                }
+                Ok(#ident {
+                    #(#field_conversions),*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn is_option_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
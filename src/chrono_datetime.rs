@@ -0,0 +1,62 @@
+//! Timezone-aware `TryFromValue` conversions, enabled by the `chrono-datetime` cargo feature.
+//!
+//! The core crate already converts date-only columns to `chrono::NaiveDate`. This adds
+//! `chrono::NaiveDateTime` and `chrono::DateTime<Utc>` for timestamp columns: a native
+//! `Value::Timestamp`/`Value::Date` column is used directly, and a text column is parsed as RFC
+//! 3339, matching how rusqlite's `chrono` integration round-trips dates.
+
+use crate::value::{TryFromValue, Value};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when a column value cannot be converted to a timezone-aware date/time.
+#[derive(Debug)]
+pub struct DateTimeConvertError {
+    message: String,
+}
+
+impl fmt::Display for DateTimeConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to convert value to date/time: {}", self.message)
+    }
+}
+
+impl Error for DateTimeConvertError {}
+
+impl TryFromValue for NaiveDateTime {
+    type Error = DateTimeConvertError;
+
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            None => Err(DateTimeConvertError {
+                message: "expecting value of type NaiveDateTime but got NULL".to_owned(),
+            }),
+            Some(Value::Timestamp(timestamp)) => Ok(timestamp),
+            Some(Value::Date(date)) => Ok(date.and_hms_opt(0, 0, 0).expect("midnight is valid")),
+            Some(Value::String(text)) => parse_rfc3339_or_sql(&text),
+            Some(value) => Err(DateTimeConvertError {
+                message: format!("expecting value convertible to NaiveDateTime but got {:?}", value),
+            }),
+        }
+    }
+}
+
+fn parse_rfc3339_or_sql(text: &str) -> Result<NaiveDateTime, DateTimeConvertError> {
+    match DateTime::parse_from_rfc3339(text) {
+        Ok(datetime) => Ok(datetime.naive_utc()),
+        Err(rfc3339_err) => NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f"))
+            .map_err(|_| DateTimeConvertError {
+                message: rfc3339_err.to_string(),
+            }),
+    }
+}
+
+impl TryFromValue for DateTime<Utc> {
+    type Error = DateTimeConvertError;
+
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        NaiveDateTime::try_from_value(value).map(|naive| Utc.from_utc_datetime(&naive))
+    }
+}
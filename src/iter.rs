@@ -0,0 +1,84 @@
+//! Fallible streaming-iterator combinators over `ResultSet`.
+//!
+//! `ResultSet` already yields `Result<Item, Error>` for each row, with `Error` unifying fetch
+//! errors from the driver and `RowConvertError` from the `TryFromValueRow` conversion. These
+//! adaptors let callers transform the converted item with a plain closure and have the first
+//! error short-circuit the iteration, instead of matching on `Result` inside a `for` loop at
+//! every call site:
+//!
+//! ```ignore
+//! let names: Result<Vec<String>, _> = result_set
+//!     .map_row(|order: Order| order.customer)
+//!     .collect();
+//! ```
+//!
+//! This is the equivalent of rusqlite's `Rows::map`/`query_and_then`, which return a `Map` type
+//! implementing `fallible_iterator::FallibleIterator`.
+
+/// Extension trait adding `map_row`/`and_then_row` to any iterator of `Result<T, E>`, in
+/// particular `ResultSet`.
+pub trait FallibleRowIteratorExt<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Map each successfully converted row with `f`, short-circuiting on the first fetch or
+    /// conversion error.
+    fn map_row<U, F>(self, f: F) -> MapRow<Self, F>
+    where
+        F: FnMut(T) -> U,
+    {
+        MapRow { iter: self, f }
+    }
+
+    /// Map each successfully converted row with a fallible `f`, short-circuiting on the first
+    /// fetch, conversion, or `f` error.
+    fn and_then_row<U, F>(self, f: F) -> AndThenRow<Self, F>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        AndThenRow { iter: self, f }
+    }
+}
+
+impl<I, T, E> FallibleRowIteratorExt<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+/// Iterator returned by [`FallibleRowIteratorExt::map_row`].
+pub struct MapRow<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, T, U, E> Iterator for MapRow<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.map(&mut self.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator returned by [`FallibleRowIteratorExt::and_then_row`].
+pub struct AndThenRow<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, T, U, E> Iterator for AndThenRow<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.and_then(&mut self.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,75 @@
+//! Conversion of rows into `serde_json::Value` objects, enabled by the `serde_json` cargo feature.
+//!
+//! This lets callers stream query results straight into a JSON API or log pipeline without
+//! hand-writing a struct per query:
+//!
+//! ```ignore
+//! for row in result_set {
+//!     let row: serde_json::Value = row?;
+//! }
+//! ```
+
+use crate::value::Value;
+use crate::value_row::{ColumnType, RowConvertError, TryFromValueRow, ValueRow};
+use chrono::{TimeZone, Utc};
+use serde_json::{Map, Number};
+
+fn value_to_json(value: Option<Value>) -> serde_json::Value {
+    match value {
+        None => serde_json::Value::Null,
+        Some(Value::Bit(b)) => serde_json::Value::Bool(b),
+        Some(Value::Tinyint(n)) => serde_json::Value::Number(Number::from(n)),
+        Some(Value::Smallint(n)) => serde_json::Value::Number(Number::from(n)),
+        Some(Value::Integer(n)) => serde_json::Value::Number(Number::from(n)),
+        Some(Value::Bigint(n)) => serde_json::Value::Number(Number::from(n)),
+        Some(Value::Float(n)) => Number::from_f64(n as f64)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Some(Value::Double(n)) => Number::from_f64(n)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Some(Value::String(s)) => serde_json::Value::String(s),
+        // `Date`/`Time` carry no timezone, so only their `Timestamp` combination can be a true
+        // RFC 3339 string (with a timezone designator); render the bare components as the
+        // matching RFC 3339 date/time subexpressions instead (`full-date`/`partial-time`, RFC
+        // 3339 section 5.6) rather than inventing a fake offset.
+        Some(Value::Date(date)) => serde_json::Value::String(date.format("%Y-%m-%d").to_string()),
+        Some(Value::Time(time)) => serde_json::Value::String(time.format("%H:%M:%S%.f").to_string()),
+        Some(Value::Timestamp(timestamp)) => serde_json::Value::String(
+            Utc.from_utc_datetime(&timestamp).to_rfc3339(),
+        ),
+        Some(Value::Bytes(bytes)) => {
+            serde_json::Value::Array(bytes.into_iter().map(|byte| serde_json::Value::Number(Number::from(byte))).collect())
+        }
+    }
+}
+
+/// Convert a row into a `serde_json::Map` keyed by column name. Keys follow the `Map`'s own
+/// ordering (sorted by name, as `serde_json` is used here without its `preserve_order`
+/// feature), not the column order of the row.
+impl TryFromValueRow for Map<String, serde_json::Value> {
+    type Error = RowConvertError;
+
+    fn try_from_row(values: ValueRow, schema: &[ColumnType]) -> Result<Self, Self::Error> {
+        if values.len() != schema.len() {
+            return Err(RowConvertError::UnexpectedNumberOfColumns {
+                expected: schema.len() as u16,
+                got: values.len(),
+            });
+        }
+        Ok(values
+            .into_iter()
+            .zip(schema.iter())
+            .map(|(value, column)| (column.name.clone(), value_to_json(value)))
+            .collect())
+    }
+}
+
+/// Convert a row into a `serde_json::Value::Object` keyed by column name.
+impl TryFromValueRow for serde_json::Value {
+    type Error = RowConvertError;
+
+    fn try_from_row(values: ValueRow, schema: &[ColumnType]) -> Result<Self, Self::Error> {
+        Map::try_from_row(values, schema).map(serde_json::Value::Object)
+    }
+}
@@ -0,0 +1,28 @@
+//! Iterator based interface to ODBC result sets, with rows convertible to Rust types via the
+//! `TryFromValueRow`/`TryFromValue` traits.
+
+pub mod iter;
+pub mod value;
+pub mod value_ref;
+pub mod value_row;
+
+#[cfg(feature = "serde_json")]
+pub mod json;
+
+#[cfg(feature = "chrono-datetime")]
+pub mod chrono_datetime;
+
+#[cfg(feature = "url")]
+pub mod url;
+
+pub use crate::iter::{AndThenRow, FallibleRowIteratorExt, MapRow};
+pub use crate::value::{TryFromValue, Value, ValueType};
+pub use crate::value_ref::{RowRef, TryFromValueRef, ValueRef};
+pub use crate::value_row::{
+    ColumnType, RowConvertError, RowConvertTupleError, TryFromValueRow, ValueRow,
+};
+
+/// Derives `TryFromValueRow` for a struct with named fields, matching fields to columns by name
+/// instead of position. See `odbc_iter_derive::TryFromValueRow` for details.
+#[cfg(feature = "derive")]
+pub use odbc_iter_derive::TryFromValueRow;
@@ -0,0 +1,46 @@
+//! `TryFromValue` conversion for `url::Url`, enabled by the `url` cargo feature.
+//!
+//! Text columns are parsed as URLs, surfacing parse failures through
+//! `RowConvertError::ValueConvertError` like any other `TryFromValue` failure.
+
+use crate::value::{TryFromValue, Value};
+use std::error::Error;
+use std::fmt;
+use url::Url;
+
+/// Error returned when a column value cannot be converted to a `Url`.
+#[derive(Debug)]
+pub enum UrlConvertError {
+    ValueConvertError(Box<dyn Error>),
+    ParseError(url::ParseError),
+}
+
+impl fmt::Display for UrlConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UrlConvertError::ValueConvertError(_) => {
+                write!(f, "failed to convert column value to target type")
+            }
+            UrlConvertError::ParseError(err) => write!(f, "failed to parse URL: {}", err),
+        }
+    }
+}
+
+impl Error for UrlConvertError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UrlConvertError::ValueConvertError(err) => Some(err.as_ref()),
+            UrlConvertError::ParseError(err) => Some(err),
+        }
+    }
+}
+
+impl TryFromValue for Url {
+    type Error = UrlConvertError;
+
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        let text = String::try_from_value(value)
+            .map_err(|err| UrlConvertError::ValueConvertError(Box::new(err)))?;
+        Url::parse(&text).map_err(UrlConvertError::ParseError)
+    }
+}
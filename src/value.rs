@@ -0,0 +1,282 @@
+//! Dynamically typed column value returned by the ODBC driver, and the `TryFromValue` trait used
+//! to convert a nullable column value to a concrete Rust type.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// Dynamically typed value of a single column in a row returned by the ODBC driver.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bit(bool),
+    Tinyint(i8),
+    Smallint(i16),
+    Integer(i32),
+    Bigint(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+}
+
+impl Value {
+    /// Convert to `i64` if this value holds an integer type representable as `i64`.
+    pub fn to_i64(&self) -> Option<i64> {
+        match *self {
+            Value::Bit(v) => Some(v as i64),
+            Value::Tinyint(v) => Some(v as i64),
+            Value::Smallint(v) => Some(v as i64),
+            Value::Integer(v) => Some(v as i64),
+            Value::Bigint(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Convert to `i32` if this value holds an integer type representable as `i32`.
+    pub fn to_i32(&self) -> Option<i32> {
+        match *self {
+            Value::Bit(v) => Some(v as i32),
+            Value::Tinyint(v) => Some(v as i32),
+            Value::Smallint(v) => Some(v as i32),
+            Value::Integer(v) => Some(v),
+            Value::Bigint(v) => i32::try_from(v).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Type tag describing a column's declared type, independent of whether a given row's value for
+/// that column is `NULL`. Used in `ColumnType` to describe row schema.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueType {
+    Bit,
+    Tinyint,
+    Smallint,
+    Integer,
+    Bigint,
+    Float,
+    Double,
+    String,
+    Bytes,
+    Date,
+    Time,
+    Timestamp,
+}
+
+/// Errors that may happen when converting a `Value` to a Rust type via `TryFromValue`.
+#[derive(Debug)]
+pub enum ValueConvertError {
+    UnexpectedNullValue(&'static str),
+    WrongValueType { expected: &'static str, value: Value },
+    ValueOutOfRange { expected: &'static str, value: Value },
+}
+
+impl fmt::Display for ValueConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValueConvertError::UnexpectedNullValue(expected) => {
+                write!(f, "expecting value of type {} but got NULL", expected)
+            }
+            ValueConvertError::WrongValueType { expected, value } => write!(
+                f,
+                "expecting value convertible to {} but got {:?}",
+                expected, value
+            ),
+            ValueConvertError::ValueOutOfRange { expected, value } => {
+                write!(f, "value {:?} out of range for {}", value, expected)
+            }
+        }
+    }
+}
+
+impl Error for ValueConvertError {}
+
+/// Conversion from a nullable column `Value` to a Rust type. Implemented for primitives, `String`,
+/// `chrono` date/time types and `Option<T>`, and used by `TryFromValueRow` to convert each column
+/// of a row.
+pub trait TryFromValue: Sized {
+    type Error: Error + 'static;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error>;
+}
+
+impl TryFromValue for Value {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        value.ok_or(ValueConvertError::UnexpectedNullValue("Value"))
+    }
+}
+
+impl<T> TryFromValue for Option<T>
+where
+    T: TryFromValue,
+{
+    type Error = T::Error;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(None),
+            some => T::try_from_value(some).map(Some),
+        }
+    }
+}
+
+impl TryFromValue for bool {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Bit(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "bool",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("bool")),
+        }
+    }
+}
+
+impl TryFromValue for String {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::String(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "String",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("String")),
+        }
+    }
+}
+
+impl TryFromValue for Vec<u8> {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Bytes(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "Vec<u8>",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("Vec<u8>")),
+        }
+    }
+}
+
+impl TryFromValue for NaiveDate {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Date(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "NaiveDate",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("NaiveDate")),
+        }
+    }
+}
+
+impl TryFromValue for NaiveTime {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Time(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "NaiveTime",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("NaiveTime")),
+        }
+    }
+}
+
+macro_rules! try_from_value_signed {
+    ($($t:ty => $expected:expr),+ $(,)?) => {
+        $(
+            impl TryFromValue for $t {
+                type Error = ValueConvertError;
+                fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+                    match value {
+                        Some(ref v) => v
+                            .to_i64()
+                            .and_then(|v| <$t>::try_from(v).ok())
+                            .ok_or_else(|| ValueConvertError::ValueOutOfRange {
+                                expected: $expected,
+                                value: value.unwrap(),
+                            }),
+                        None => Err(ValueConvertError::UnexpectedNullValue($expected)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+try_from_value_signed! {
+    i8 => "i8",
+    i16 => "i16",
+    i32 => "i32",
+    i64 => "i64",
+}
+
+macro_rules! try_from_value_unsigned {
+    ($($t:ty => $expected:expr),+ $(,)?) => {
+        $(
+            impl TryFromValue for $t {
+                type Error = ValueConvertError;
+                fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+                    match value {
+                        Some(ref v) => v
+                            .to_i64()
+                            .and_then(|v| <$t>::try_from(v).ok())
+                            .ok_or_else(|| ValueConvertError::ValueOutOfRange {
+                                expected: $expected,
+                                value: value.unwrap(),
+                            }),
+                        None => Err(ValueConvertError::UnexpectedNullValue($expected)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+try_from_value_unsigned! {
+    u8 => "u8",
+    u16 => "u16",
+    u32 => "u32",
+    u64 => "u64",
+}
+
+impl TryFromValue for f32 {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Float(v)) => Ok(v),
+            Some(Value::Double(v)) => Ok(v as f32),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "f32",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("f32")),
+        }
+    }
+}
+
+impl TryFromValue for f64 {
+    type Error = ValueConvertError;
+    fn try_from_value(value: Option<Value>) -> Result<Self, Self::Error> {
+        match value {
+            Some(Value::Float(v)) => Ok(v as f64),
+            Some(Value::Double(v)) => Ok(v),
+            Some(value) => Err(ValueConvertError::WrongValueType {
+                expected: "f64",
+                value,
+            }),
+            None => Err(ValueConvertError::UnexpectedNullValue("f64")),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Borrowed, zero-copy counterparts of [`Value`](crate::value::Value) and row types.
+//!
+//! Building a [`ValueRow`](crate::value_row::ValueRow) allocates a `Vec` and an owned `Value`
+//! (including a `String` for text columns) for every row. When the caller only needs to parse a
+//! number or copy data into their own buffer, this is wasted work on wide, high-row-count result
+//! sets. `ValueRef<'a>` borrows the column's backing bytes/slices instead of cloning them, and
+//! `RowRef<'a>` exposes `get` (by index) and `get_by_name` (by schema column name) access without
+//! allocating owned `Value`s. This mirrors the borrowed `ValueRef`/`ToSqlOutput::Borrowed` design used by
+//! rusqlite.
+//!
+//! The existing owned [`TryFromValueRow`](crate::value_row::TryFromValueRow) path keeps working
+//! unchanged: [`RowRef::borrow`] builds a borrowed view over an existing [`ValueRow`], and
+//! [`RowRef::to_owned`] clones it back into a [`ValueRow`] (via [`ValueRef::to_owned`] on each
+//! column) for callers that need to hand the row off to that path.
+
+use crate::value::Value;
+use crate::value_row::{ColumnType, ValueRow};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::convert::TryFrom;
+use std::error::Error;
+
+/// Borrowed analog of [`Value`] that references bytes owned elsewhere, avoiding a per-row
+/// allocation for variable length data. Within this crate it is produced by [`ValueRef::borrow`]
+/// from an owned [`Value`]; nothing here builds one over ODBC column buffers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    Bit(bool),
+    Tinyint(i8),
+    Smallint(i16),
+    Integer(i32),
+    Bigint(i64),
+    Float(f32),
+    Double(f64),
+    Str(&'a str),
+    Bytes(&'a [u8]),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+}
+
+impl<'a> ValueRef<'a> {
+    /// Borrow a `ValueRef` from an owned [`Value`] without cloning its data.
+    pub fn borrow(value: &'a Value) -> Self {
+        match value {
+            Value::Bit(v) => ValueRef::Bit(*v),
+            Value::Tinyint(v) => ValueRef::Tinyint(*v),
+            Value::Smallint(v) => ValueRef::Smallint(*v),
+            Value::Integer(v) => ValueRef::Integer(*v),
+            Value::Bigint(v) => ValueRef::Bigint(*v),
+            Value::Float(v) => ValueRef::Float(*v),
+            Value::Double(v) => ValueRef::Double(*v),
+            Value::String(v) => ValueRef::Str(v.as_str()),
+            Value::Bytes(v) => ValueRef::Bytes(v.as_slice()),
+            Value::Date(v) => ValueRef::Date(*v),
+            Value::Time(v) => ValueRef::Time(*v),
+            Value::Timestamp(v) => ValueRef::Timestamp(*v),
+        }
+    }
+
+    /// Clone the borrowed data into an owned [`Value`].
+    pub fn to_owned(&self) -> Value {
+        match *self {
+            ValueRef::Bit(v) => Value::Bit(v),
+            ValueRef::Tinyint(v) => Value::Tinyint(v),
+            ValueRef::Smallint(v) => Value::Smallint(v),
+            ValueRef::Integer(v) => Value::Integer(v),
+            ValueRef::Bigint(v) => Value::Bigint(v),
+            ValueRef::Float(v) => Value::Float(v),
+            ValueRef::Double(v) => Value::Double(v),
+            ValueRef::Str(v) => Value::String(v.to_owned()),
+            ValueRef::Bytes(v) => Value::Bytes(v.to_owned()),
+            ValueRef::Date(v) => Value::Date(v),
+            ValueRef::Time(v) => Value::Time(v),
+            ValueRef::Timestamp(v) => Value::Timestamp(v),
+        }
+    }
+
+    /// Convert to `i64` if this value holds an integer type representable as `i64`.
+    pub fn to_i64(&self) -> Option<i64> {
+        match *self {
+            ValueRef::Bit(v) => Some(v as i64),
+            ValueRef::Tinyint(v) => Some(v as i64),
+            ValueRef::Smallint(v) => Some(v as i64),
+            ValueRef::Integer(v) => Some(v as i64),
+            ValueRef::Bigint(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Row of borrowed column values, built by [`RowRef::borrow`] over a [`ValueRow`] and valid for
+/// that row's lifetime. No constructor reads ODBC column buffers directly.
+#[derive(Debug, Clone)]
+pub struct RowRef<'a> {
+    values: Vec<Option<ValueRef<'a>>>,
+    schema: &'a [ColumnType],
+}
+
+impl<'a> RowRef<'a> {
+    /// Build a borrowed view over `row`, avoiding a clone of each column's data.
+    pub fn borrow(row: &'a ValueRow, schema: &'a [ColumnType]) -> Self {
+        RowRef {
+            values: row
+                .iter()
+                .map(|value| value.as_ref().map(ValueRef::borrow))
+                .collect(),
+            schema,
+        }
+    }
+
+    /// Get the value of the column at `index`, or `None` if out of range.
+    pub fn get(&self, index: usize) -> Option<Option<ValueRef<'a>>> {
+        self.values.get(index).copied()
+    }
+
+    /// Get the value of the column named `name` (case-insensitive), or `None` if no such column
+    /// exists in the schema.
+    pub fn get_by_name(&self, name: &str) -> Option<Option<ValueRef<'a>>> {
+        self.schema
+            .iter()
+            .position(|column| column.name.eq_ignore_ascii_case(name))
+            .and_then(|index| self.get(index))
+    }
+
+    /// The schema describing this row's columns.
+    pub fn schema(&self) -> &'a [ColumnType] {
+        self.schema
+    }
+
+    /// Clone every column back into an owned [`ValueRow`], e.g. to hand the row off to the
+    /// existing [`TryFromValueRow`](crate::value_row::TryFromValueRow) machinery.
+    pub fn to_owned(&self) -> ValueRow {
+        self.values
+            .iter()
+            .map(|value| value.as_ref().map(ValueRef::to_owned))
+            .collect()
+    }
+}
+
+/// Borrowed analog of [`TryFromValue`](crate::value::TryFromValue), converting directly from a
+/// [`ValueRef`]. Borrowed targets such as `&'a str` avoid a copy; owned targets such as `String`
+/// and `Vec<u8>` copy the borrowed data.
+pub trait TryFromValueRef<'a>: Sized {
+    type Error: Error + 'static;
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error>;
+}
+
+impl<'a, T> TryFromValueRef<'a> for Option<T>
+where
+    T: TryFromValueRef<'a>,
+{
+    type Error = T::Error;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(None),
+            some => T::try_from_value_ref(some).map(Some),
+        }
+    }
+}
+
+/// Error returned when a `ValueRef` does not hold the expected variant, or is `NULL` where a
+/// value was required.
+#[derive(Debug)]
+pub enum ValueRefConvertError {
+    UnexpectedNullValue(&'static str),
+    WrongValueType(&'static str),
+    ValueOutOfRange(&'static str),
+}
+
+impl std::fmt::Display for ValueRefConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValueRefConvertError::UnexpectedNullValue(expected) => {
+                write!(f, "expecting value of type {} but got NULL", expected)
+            }
+            ValueRefConvertError::WrongValueType(expected) => {
+                write!(f, "expecting value convertible to {}", expected)
+            }
+            ValueRefConvertError::ValueOutOfRange(expected) => {
+                write!(f, "value out of range for {}", expected)
+            }
+        }
+    }
+}
+
+impl Error for ValueRefConvertError {}
+
+impl<'a> TryFromValueRef<'a> for &'a str {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Str(s)) => Ok(s),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("&str")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("&str")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for i64 {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Bit(v)) => Ok(v as i64),
+            Some(ValueRef::Tinyint(v)) => Ok(v as i64),
+            Some(ValueRef::Smallint(v)) => Ok(v as i64),
+            Some(ValueRef::Integer(v)) => Ok(v as i64),
+            Some(ValueRef::Bigint(v)) => Ok(v),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("i64")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("i64")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for f64 {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Float(v)) => Ok(v as f64),
+            Some(ValueRef::Double(v)) => Ok(v),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("f64")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("f64")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for f32 {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Float(v)) => Ok(v),
+            Some(ValueRef::Double(v)) => Ok(v as f32),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("f32")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("f32")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for bool {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Bit(v)) => Ok(v),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("bool")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("bool")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for String {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Str(s)) => Ok(s.to_owned()),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("String")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("String")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for Vec<u8> {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Bytes(b)) => Ok(b.to_owned()),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("Vec<u8>")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("Vec<u8>")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for NaiveDate {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Date(d)) => Ok(d),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("NaiveDate")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("NaiveDate")),
+        }
+    }
+}
+
+impl<'a> TryFromValueRef<'a> for NaiveTime {
+    type Error = ValueRefConvertError;
+
+    fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+        match value {
+            Some(ValueRef::Time(t)) => Ok(t),
+            Some(_) => Err(ValueRefConvertError::WrongValueType("NaiveTime")),
+            None => Err(ValueRefConvertError::UnexpectedNullValue("NaiveTime")),
+        }
+    }
+}
+
+macro_rules! try_from_value_ref_signed {
+    ($($t:ty => $expected:expr),+ $(,)?) => {
+        $(
+            impl<'a> TryFromValueRef<'a> for $t {
+                type Error = ValueRefConvertError;
+
+                fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+                    match value {
+                        Some(v) => v
+                            .to_i64()
+                            .and_then(|v| <$t>::try_from(v).ok())
+                            .ok_or(ValueRefConvertError::ValueOutOfRange($expected)),
+                        None => Err(ValueRefConvertError::UnexpectedNullValue($expected)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+try_from_value_ref_signed! {
+    i8 => "i8",
+    i16 => "i16",
+    i32 => "i32",
+}
+
+macro_rules! try_from_value_ref_unsigned {
+    ($($t:ty => $expected:expr),+ $(,)?) => {
+        $(
+            impl<'a> TryFromValueRef<'a> for $t {
+                type Error = ValueRefConvertError;
+
+                fn try_from_value_ref(value: Option<ValueRef<'a>>) -> Result<Self, Self::Error> {
+                    match value {
+                        Some(v) => v
+                            .to_i64()
+                            .and_then(|v| <$t>::try_from(v).ok())
+                            .ok_or(ValueRefConvertError::ValueOutOfRange($expected)),
+                        None => Err(ValueRefConvertError::UnexpectedNullValue($expected)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+try_from_value_ref_unsigned! {
+    u8 => "u8",
+    u16 => "u16",
+    u32 => "u32",
+    u64 => "u64",
+}
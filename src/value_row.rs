@@ -25,10 +25,12 @@ pub struct ColumnType {
 /// Also this trait implementation allows to convert single column rows to types implementing `TryFromValue`.
 /// 
 /// This trait can be implemented for custom objects. This will enable them to be queried directly from database as `Item` of `ResultSet` iterator.
+///
+/// Rather than implementing this trait by hand, structs with named fields can derive it with `#[derive(TryFromValueRow)]` (re-exported from the `odbc-iter-derive` crate) to map rows to fields by column name instead of position.
 pub trait TryFromValueRow: Sized {
     type Error: Error + 'static;
     /// Given `ColumnType` convert from `ValueRow` to other type of value representing table row.
-    fn try_from_row<'n>(values: ValueRow, schema: &'n [ColumnType]) -> Result<Self, Self::Error>;
+    fn try_from_row(values: ValueRow, schema: &[ColumnType]) -> Result<Self, Self::Error>;
 }
 
 /// Errors that may happen during conversion of `ValueRow` to given type.
@@ -37,6 +39,7 @@ pub enum RowConvertError {
     UnexpectedNullValue(&'static str),
     UnexpectedValue,
     UnexpectedNumberOfColumns { expected: u16, got: usize },
+    ColumnNotFound(&'static str),
     ValueConvertError(Box<dyn Error>),
 }
 
@@ -52,6 +55,9 @@ impl fmt::Display for RowConvertError {
                 "unexpected number of columns: expected {} but got {}",
                 expected, got
             ),
+            RowConvertError::ColumnNotFound(name) => {
+                write!(f, "no column named {} found in row schema", name)
+            }
             RowConvertError::ValueConvertError(_) => {
                 write!(f, "failed to convert column value to target type")
             }
@@ -64,7 +70,8 @@ impl Error for RowConvertError {
         match self {
             RowConvertError::UnexpectedNullValue(_)
             | RowConvertError::UnexpectedValue
-            | RowConvertError::UnexpectedNumberOfColumns { .. } => None,
+            | RowConvertError::UnexpectedNumberOfColumns { .. }
+            | RowConvertError::ColumnNotFound(_) => None,
             RowConvertError::ValueConvertError(err) => Some(err.as_ref()),
         }
     }
@@ -73,7 +80,7 @@ impl Error for RowConvertError {
 /// Allow to retrieve unconverted `ValueRow` as item of `ResultSet` iterator.
 impl TryFromValueRow for ValueRow {
     type Error = Infallible;
-    fn try_from_row<'n>(values: ValueRow, _schema: &'n [ColumnType]) -> Result<Self, Self::Error> {
+    fn try_from_row(values: ValueRow, _schema: &[ColumnType]) -> Result<Self, Self::Error> {
         Ok(values)
     }
 }
@@ -81,7 +88,7 @@ impl TryFromValueRow for ValueRow {
 /// Unit can be used to signal that no rows of data should be produced.
 impl TryFromValueRow for () {
     type Error = RowConvertError;
-    fn try_from_row<'n>(_values: ValueRow, _schema: &'n [ColumnType]) -> Result<Self, Self::Error> {
+    fn try_from_row(_values: ValueRow, _schema: &[ColumnType]) -> Result<Self, Self::Error> {
         Err(RowConvertError::UnexpectedValue)
     }
 }
@@ -92,9 +99,9 @@ where
     T: TryFromValue,
 {
     type Error = RowConvertError;
-    fn try_from_row<'n>(
+    fn try_from_row(
         mut values: ValueRow,
-        _schema: &'n [ColumnType],
+        _schema: &[ColumnType],
     ) -> Result<Self, Self::Error> {
         if values.len() != 1 {
             return Err(RowConvertError::UnexpectedNumberOfColumns {
@@ -157,7 +164,7 @@ macro_rules! try_from_tuple {
         $(
             impl<$($T: TryFromValue),+> TryFromValueRow for ($($T,)+) {
                 type Error = RowConvertTupleError;
-                fn try_from_row<'n>(values: ValueRow, _schema: &'n [ColumnType]) -> Result<($($T,)+), Self::Error> {
+                fn try_from_row(values: ValueRow, _schema: &[ColumnType]) -> Result<($($T,)+), Self::Error> {
                     if values.len() != count!($($T)+) {
                         return Err(RowConvertTupleError::UnexpectedNumberOfColumns { expected: values.len() as u16, tuple: stringify![($($T,)+)] })
                     }
@@ -274,311 +281,11 @@ try_from_tuple! {
     }
 }
 
-//TODO: this tests should not need DB
 #[cfg(test)]
 mod tests {
-    #[allow(unused_imports)]
     use super::*;
-    #[allow(unused_imports)]
-    use crate::Odbc;
-    #[allow(unused_imports)]
-    use assert_matches::assert_matches;
-
-    #[derive(Debug)]
-    struct Foo {
-        val: i64,
-    }
-
-    impl TryFromValueRow for Foo {
-        type Error = Infallible;
-        fn try_from_row<'n>(
-            mut values: ValueRow,
-            _schema: &'n [ColumnType],
-        ) -> Result<Self, Self::Error> {
-            Ok(values
-                .pop()
-                .map(|val| Foo {
-                    val: val.and_then(|v| v.to_i64()).expect("val to be an bigint"),
-                })
-                .expect("value"))
-        }
-    }
-
     use crate::value::Value;
 
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_custom_type() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let foo: Foo = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT) AS val;")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(foo.val, 42);
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_value() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: Value = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.to_i64().unwrap(), 42);
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_nullable_value() {
-        let mut db = crate::tests::connect_monetdb();;
-
-        let value: Option<Value> = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_some());
-        assert_eq!(value.unwrap().to_i64().unwrap(), 42);
-
-        let value: Option<Value> = db
-            .handle()
-            .query("SELECT CAST(NULL AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_none());
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_value_row() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: ValueRow = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT), CAST(22 AS INTEGER)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.len(), 2);
-        assert_eq!(value[0].as_ref().unwrap().to_i64().unwrap(), 42);
-        assert_eq!(value[1].as_ref().unwrap().to_i32().unwrap(), 22);
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_copy() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: bool = db
-            .handle()
-            .query("SELECT true")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value, true);
-
-        let value: Option<bool> = db
-            .handle()
-            .query("SELECT true")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.unwrap(), true);
-
-        let value: Option<bool> = db
-            .handle()
-            .query("SELECT CAST(NULL AS BOOL)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_none());
-
-        let value: i64 = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value, 42);
-
-        let value: Option<i64> = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.unwrap(), 42i64);
-
-        let value: Option<i64> = db
-            .handle()
-            .query("SELECT CAST(NULL AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_none());
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_unsigned() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: Option<u64> = db
-            .handle()
-            .query("SELECT CAST(42 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.unwrap(), 42u64);
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    #[should_panic(expected = "ValueOutOfRange")]
-    fn test_single_unsigned_err() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let _value: Option<u64> = db
-            .handle()
-            .query("SELECT CAST(-666 AS BIGINT)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_string() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: String = db
-            .handle()
-            .query("SELECT 'foo'")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(&value, "foo");
-
-        let value: Option<String> = db
-            .handle()
-            .query("SELECT 'foo'")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(&value.unwrap(), "foo");
-
-        let value: Option<String> = db
-            .handle()
-            .query("SELECT CAST(NULL AS STRING)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_none());
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_single_date() {
-        use chrono::Datelike;
-        use chrono::NaiveDate;
-
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: NaiveDate = db
-            .handle()
-            .query("SELECT CAST('2019-04-02' AS DATE)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.year(), 2019);
-        assert_eq!(value.month(), 4);
-        assert_eq!(value.day(), 2);
-
-        let value: Option<NaiveDate> = db
-            .handle()
-            .query("SELECT CAST('2019-04-02' AS DATE)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(value.unwrap().year(), 2019);
-        assert_eq!(value.unwrap().month(), 4);
-        assert_eq!(value.unwrap().day(), 2);
-
-        let value: Option<NaiveDate> = db
-            .handle()
-            .query("SELECT CAST(NULL AS DATE)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(value.is_none());
-    }
-
-    #[test]
-    #[cfg(feature = "test-monetdb")]
-    fn test_tuple_value() {
-        let mut db = crate::tests::connect_monetdb();
-
-        let value: (String, i64, bool) = db
-            .handle()
-            .query("SELECT 'foo', CAST(42 AS BIGINT), true")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(&value.0, "foo");
-        assert_eq!(value.1, 42);
-        assert_eq!(value.2, true);
-
-        let value: (Option<String>, i64, Option<bool>) = db
-            .handle()
-            .query("SELECT 'foo', CAST(42 AS BIGINT), true")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert_eq!(&value.0.unwrap(), "foo");
-        assert_eq!(value.1, 42);
-        assert_eq!(value.2.unwrap(), true);
-
-        let value: (Option<String>, i64, Option<bool>) = db
-            .handle()
-            .query("SELECT CAST(NULL AS STRING), CAST(42 AS BIGINT), CAST(NULL AS BOOL)")
-            .expect("failed to run query")
-            .single()
-            .expect("fetch data");
-
-        assert!(&value.0.is_none());
-        assert_eq!(value.1, 42);
-        assert!(value.2.is_none());
-    }
-
     #[test]
     fn test_value_row_conversions() {
         let test_row: ValueRow = vec![Some(Value::Bit(true)), Some(Value::Integer(42)), None];
@@ -0,0 +1,56 @@
+//! Integration tests for the `chrono-datetime` feature's `TryFromValue` conversions.
+
+#![cfg(feature = "chrono-datetime")]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use odbc_iter::{TryFromValue, Value};
+
+fn naive_datetime(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, s)
+        .unwrap()
+}
+
+#[test]
+fn converts_native_timestamp_value() {
+    let timestamp = naive_datetime(2020, 1, 2, 3, 4, 5);
+
+    let value = NaiveDateTime::try_from_value(Some(Value::Timestamp(timestamp))).expect("converts");
+
+    assert_eq!(value, timestamp);
+}
+
+#[test]
+fn converts_native_date_value_to_midnight() {
+    let date = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+
+    let value = NaiveDateTime::try_from_value(Some(Value::Date(date))).expect("converts");
+
+    assert_eq!(value, date.and_hms_opt(0, 0, 0).unwrap());
+}
+
+#[test]
+fn parses_rfc3339_text_value() {
+    let value = NaiveDateTime::try_from_value(Some(Value::String(
+        "2020-01-02T03:04:05Z".to_owned(),
+    )))
+    .expect("converts");
+
+    assert_eq!(value, naive_datetime(2020, 1, 2, 3, 4, 5));
+}
+
+#[test]
+fn converts_to_utc_date_time() {
+    let timestamp = naive_datetime(2020, 1, 2, 3, 4, 5);
+
+    let value: DateTime<Utc> =
+        DateTime::<Utc>::try_from_value(Some(Value::Timestamp(timestamp))).expect("converts");
+
+    assert_eq!(value, Utc.from_utc_datetime(&timestamp));
+}
+
+#[test]
+fn rejects_null() {
+    assert!(NaiveDateTime::try_from_value(None).is_err());
+}
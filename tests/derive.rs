@@ -0,0 +1,96 @@
+//! Integration tests for `#[derive(TryFromValueRow)]`, requiring the `derive` cargo feature.
+//!
+//! These exercise the generated `try_from_row` directly against hand built `ValueRow`/`ColumnType`
+//! data, mirroring `test_value_row_conversions` in `src/value_row.rs`, so no database is required.
+
+#![cfg(feature = "derive")]
+
+use odbc_iter::{ColumnType, TryFromValueRow, Value, ValueType};
+
+#[derive(Debug, TryFromValueRow)]
+struct Order {
+    id: i64,
+    customer: Option<String>,
+    placed: String,
+}
+
+fn schema() -> Vec<ColumnType> {
+    vec![
+        ColumnType {
+            value_type: ValueType::Integer,
+            nullable: false,
+            name: "id".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::String,
+            nullable: true,
+            name: "customer".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::String,
+            nullable: false,
+            name: "placed".to_owned(),
+        },
+    ]
+}
+
+#[test]
+fn maps_fields_by_column_name_regardless_of_order() {
+    // Columns are in a different order than the struct fields on purpose.
+    let schema = vec![
+        schema()[1].clone(),
+        schema()[2].clone(),
+        schema()[0].clone(),
+    ];
+    let values = vec![
+        Some(Value::String("Alice".to_owned())),
+        Some(Value::String("2020-01-01".to_owned())),
+        Some(Value::Integer(42)),
+    ];
+
+    let order = Order::try_from_row(values, &schema).expect("row converts");
+
+    assert_eq!(order.id, 42);
+    assert_eq!(order.customer.as_deref(), Some("Alice"));
+    assert_eq!(order.placed, "2020-01-01");
+}
+
+#[test]
+fn tolerates_null_in_optional_field() {
+    let schema = schema();
+    let values = vec![Some(Value::Integer(1)), None, Some(Value::String("2020-01-01".to_owned()))];
+
+    let order = Order::try_from_row(values, &schema).expect("row converts");
+
+    assert_eq!(order.customer, None);
+}
+
+#[test]
+fn rejects_null_in_required_field() {
+    use odbc_iter::RowConvertError;
+
+    let schema = schema();
+    let values = vec![None, Some(Value::String("Alice".to_owned())), Some(Value::String("2020-01-01".to_owned()))];
+
+    let err = Order::try_from_row(values, &schema).expect_err("NULL id should fail");
+
+    match err {
+        RowConvertError::UnexpectedNullValue(field) => assert_eq!(field, "id"),
+        other => panic!("expected UnexpectedNullValue, got {:?}", other),
+    }
+}
+
+#[test]
+fn reports_missing_column() {
+    use odbc_iter::RowConvertError;
+
+    let schema = vec![schema()[0].clone(), schema()[1].clone()];
+    let values = vec![Some(Value::Integer(1)), Some(Value::String("Alice".to_owned()))];
+
+    let err = Order::try_from_row(values, &schema).expect_err("missing placed column should fail");
+
+    match err {
+        RowConvertError::ColumnNotFound(column) => assert_eq!(column, "placed"),
+        other => panic!("expected ColumnNotFound, got {:?}", other),
+    }
+}
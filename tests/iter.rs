@@ -0,0 +1,61 @@
+//! Integration tests for the `map_row`/`and_then_row` fallible iterator combinators.
+
+use odbc_iter::FallibleRowIteratorExt;
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq)]
+struct FetchError(&'static str);
+
+#[test]
+fn map_row_transforms_each_ok_item() {
+    let rows: Vec<Result<i64, FetchError>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    let doubled: Result<Vec<i64>, FetchError> =
+        rows.into_iter().map_row(|row| row * 2).collect();
+
+    assert_eq!(doubled, Ok(vec![2, 4, 6]));
+}
+
+#[test]
+fn map_row_short_circuits_on_first_error() {
+    let rows: Vec<Result<i64, FetchError>> = vec![Ok(1), Err(FetchError("fetch failed")), Ok(3)];
+
+    let mut seen = Vec::new();
+    let result: Result<Vec<i64>, FetchError> = rows
+        .into_iter()
+        .map_row(|row| {
+            seen.push(row);
+            row * 2
+        })
+        .collect();
+
+    assert_eq!(result, Err(FetchError("fetch failed")));
+    // The row after the error is never reached.
+    assert_eq!(seen, vec![1]);
+}
+
+#[test]
+fn and_then_row_propagates_closure_errors() {
+    let rows: Vec<Result<i64, FetchError>> = vec![Ok(1), Ok(-2), Ok(3)];
+
+    let result: Result<Vec<u64>, FetchError> = rows
+        .into_iter()
+        .and_then_row(|row| {
+            u64::try_from(row).map_err(|_| FetchError("negative value"))
+        })
+        .collect();
+
+    assert_eq!(result, Err(FetchError("negative value")));
+}
+
+#[test]
+fn and_then_row_collects_all_ok_values() {
+    let rows: Vec<Result<i64, FetchError>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    let result: Result<Vec<u64>, FetchError> = rows
+        .into_iter()
+        .and_then_row(|row| u64::try_from(row).map_err(|_| FetchError("negative value")))
+        .collect();
+
+    assert_eq!(result, Ok(vec![1, 2, 3]));
+}
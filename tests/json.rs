@@ -0,0 +1,71 @@
+//! Integration tests for the `serde_json` feature's `TryFromValueRow` conversions.
+
+#![cfg(feature = "serde_json")]
+
+use chrono::NaiveDate;
+use odbc_iter::{ColumnType, TryFromValueRow, Value, ValueType};
+use serde_json::json;
+
+fn schema() -> Vec<ColumnType> {
+    vec![
+        ColumnType {
+            value_type: ValueType::Integer,
+            nullable: false,
+            name: "id".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::String,
+            nullable: true,
+            name: "name".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::Date,
+            nullable: true,
+            name: "created".to_owned(),
+        },
+    ]
+}
+
+#[test]
+fn converts_row_to_json_object_keyed_by_column_name() {
+    let values = vec![
+        Some(Value::Integer(1)),
+        Some(Value::String("Alice".to_owned())),
+        Some(Value::Date(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap())),
+    ];
+
+    let row: serde_json::Value =
+        serde_json::Value::try_from_row(values, &schema()).expect("row converts");
+
+    assert_eq!(
+        row,
+        json!({"id": 1, "name": "Alice", "created": "2020-01-02"})
+    );
+}
+
+#[test]
+fn null_columns_convert_to_json_null() {
+    let values = vec![Some(Value::Integer(1)), None, None];
+
+    let row: serde_json::Value =
+        serde_json::Value::try_from_row(values, &schema()).expect("row converts");
+
+    assert_eq!(row, json!({"id": 1, "name": null, "created": null}));
+}
+
+#[test]
+fn reports_mismatched_column_count() {
+    use odbc_iter::RowConvertError;
+
+    let values = vec![Some(Value::Integer(1)), Some(Value::String("Alice".to_owned()))];
+
+    let err = serde_json::Value::try_from_row(values, &schema()).expect_err("row should not convert");
+
+    match err {
+        RowConvertError::UnexpectedNumberOfColumns { expected, got } => {
+            assert_eq!(expected, 3);
+            assert_eq!(got, 2);
+        }
+        other => panic!("expected UnexpectedNumberOfColumns, got {:?}", other),
+    }
+}
@@ -0,0 +1,27 @@
+//! Integration tests for the `url` feature's `TryFromValue` conversion.
+
+#![cfg(feature = "url")]
+
+use odbc_iter::{TryFromValue, Value};
+use url::Url;
+
+#[test]
+fn parses_valid_url_text_value() {
+    let value = Url::try_from_value(Some(Value::String("https://example.com/path".to_owned())))
+        .expect("converts");
+
+    assert_eq!(value.as_str(), "https://example.com/path");
+}
+
+#[test]
+fn rejects_malformed_url_text() {
+    let err = Url::try_from_value(Some(Value::String("not a url".to_owned())))
+        .expect_err("malformed URL should fail to parse");
+
+    assert!(err.to_string().contains("failed to parse URL"));
+}
+
+#[test]
+fn rejects_null() {
+    assert!(Url::try_from_value(None).is_err());
+}
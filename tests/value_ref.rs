@@ -0,0 +1,79 @@
+//! Integration tests for the borrowed `ValueRef`/`RowRef` layer.
+
+use odbc_iter::value_ref::{RowRef, TryFromValueRef, ValueRef};
+use odbc_iter::{ColumnType, Value, ValueType};
+
+fn schema() -> Vec<ColumnType> {
+    vec![
+        ColumnType {
+            value_type: ValueType::Integer,
+            nullable: false,
+            name: "id".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::String,
+            nullable: true,
+            name: "name".to_owned(),
+        },
+    ]
+}
+
+#[test]
+fn borrows_values_without_cloning_strings() {
+    let row = vec![
+        Some(Value::Bigint(42)),
+        Some(Value::String("Alice".to_owned())),
+    ];
+    let schema = schema();
+
+    let row_ref = RowRef::borrow(&row, &schema);
+
+    assert_eq!(row_ref.get(0), Some(Some(ValueRef::Bigint(42))));
+    assert_eq!(row_ref.get_by_name("name"), Some(Some(ValueRef::Str("Alice"))));
+    assert_eq!(row_ref.get_by_name("NAME"), Some(Some(ValueRef::Str("Alice"))));
+    assert_eq!(row_ref.get_by_name("missing"), None);
+
+    // The borrowed `&str` really does point into the original `String`'s allocation.
+    if let Some(Some(ValueRef::Str(s))) = row_ref.get_by_name("name") {
+        if let Some(Value::String(original)) = &row[1] {
+            assert_eq!(s.as_ptr(), original.as_ptr());
+        } else {
+            panic!("expected Value::String");
+        }
+    } else {
+        panic!("expected ValueRef::Str");
+    }
+}
+
+#[test]
+fn round_trips_through_to_owned() {
+    let row = vec![Some(Value::Bit(true)), None];
+    let schema = vec![
+        ColumnType {
+            value_type: ValueType::Bit,
+            nullable: false,
+            name: "flag".to_owned(),
+        },
+        ColumnType {
+            value_type: ValueType::Integer,
+            nullable: true,
+            name: "maybe".to_owned(),
+        },
+    ];
+
+    let row_ref = RowRef::borrow(&row, &schema);
+
+    assert_eq!(row_ref.to_owned(), row);
+}
+
+#[test]
+fn try_from_value_ref_converts_and_rejects_null() {
+    let present = i64::try_from_value_ref(Some(ValueRef::Integer(7))).expect("converts");
+    assert_eq!(present, 7);
+
+    let missing = i64::try_from_value_ref(None);
+    assert!(missing.is_err());
+
+    let optional = Option::<i64>::try_from_value_ref(None).expect("NULL tolerated");
+    assert_eq!(optional, None);
+}